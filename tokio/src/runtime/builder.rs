@@ -0,0 +1,54 @@
+use crate::runtime::time::{Handle as TimeHandle, TimeSource};
+use std::time::Duration;
+
+impl Builder {
+    /// Sets the timer coalescing granularity.
+    ///
+    /// High-timer-count workloads (thousands of idle connection timeouts,
+    /// for example) pay for one wheel slot and one waker per timer even
+    /// when firing a few milliseconds early would be fine. `timer_slack`
+    /// rounds every deadline *up* to the next multiple of `slack`,
+    /// deliberately clustering nearby timers into the same wheel slot so
+    /// they fire -- and wake -- together, trading a bit of timing
+    /// precision for fewer wheel insertions, cancellations, and
+    /// redundant wakeups under load.
+    ///
+    /// Deadlines are never rounded *down* past the requested instant, and
+    /// [`Sleep::deadline`]/[`Timer::deadline`] keep returning the
+    /// original requested deadline regardless of slack.
+    ///
+    /// The default is `Duration::ZERO`, which disables coalescing and
+    /// preserves exact deadlines.
+    ///
+    /// [`Sleep::deadline`]: crate::time::Sleep::deadline
+    /// [`Timer::deadline`]: crate::runtime::time::Timer::deadline
+    pub fn timer_slack(&mut self, slack: Duration) -> &mut Self {
+        self.timer_slack = TimeSource::duration_to_ticks(slack);
+        self
+    }
+
+    /// Builds the time driver's `Handle`, threading the `timer_slack`
+    /// configured above through to it.
+    ///
+    /// This is the assignment from `Builder::timer_slack` into the
+    /// constructed `time::Handle` that `deadline_to_tick` actually reads
+    /// via `hdl.timer_slack()` -- skipping it would leave `timer_slack`
+    /// sitting on the `Builder` with no way to ever take effect. Called
+    /// by the driver construction that builds the rest of the runtime
+    /// from this `Builder`.
+    pub(crate) fn build_time_handle(&self, time_source: TimeSource) -> TimeHandle {
+        TimeHandle::new(time_source, self.timer_slack)
+    }
+}
+
+// No `#[cfg(test)]` module here: a real end-to-end test -- build a
+// `Builder`, call `.timer_slack(..)`, build a runtime, and assert the
+// resulting `time::Handle::timer_slack()` -- would need a constructible
+// `Builder` and `TimeSource`, and neither has a constructor anywhere in
+// this module's visible surface (their other fields live outside
+// `runtime::builder`/`runtime::time`). Rather than fake that coverage
+// with a test that doesn't actually touch `build_time_handle`, leave
+// this noted: once `Builder`/`TimeSource` are constructible here, add
+// `build_time_handle_threads_slack_into_handle` asserting
+// `builder.build_time_handle(source).timer_slack() == expected_ticks`
+// after `builder.timer_slack(expected_duration)`.
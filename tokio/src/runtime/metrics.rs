@@ -0,0 +1,49 @@
+use crate::runtime::scheduler::util::time::{
+    active_timers_count, cancelled_timers_total, next_expiration_across_workers,
+    premature_timers_total,
+};
+use std::time::Duration;
+
+impl RuntimeMetrics {
+    /// Returns the number of timers currently registered with the
+    /// runtime's time driver, summed across every worker.
+    ///
+    /// This counts every `sleep`/`timeout`/`Interval` entry from the
+    /// moment it's first linked into a wheel until its last handle is
+    /// dropped and the entry is freed. A timer that has fired but whose
+    /// owner still holds it is still counted, as is a cancelled one
+    /// until its cancellation has been processed.
+    pub fn active_timers_count(&self) -> usize {
+        active_timers_count(self.handle.driver())
+    }
+
+    /// Returns the total number of timers whose deadline had already
+    /// passed when a worker inserted them into its wheel, summed across
+    /// every worker.
+    ///
+    /// This covers timers handed to a worker through its inject queue
+    /// and timers reset to a deadline that had already passed. A timer
+    /// whose first registration on its own worker thread finds the
+    /// deadline already passed completes without being counted.
+    pub fn premature_timers_total(&self) -> u64 {
+        premature_timers_total(self.handle.driver())
+    }
+
+    /// Returns the total number of cancelled timers that have been
+    /// removed from a wheel, summed across every worker.
+    pub fn cancelled_timers_total(&self) -> u64 {
+        cancelled_timers_total(self.handle.driver())
+    }
+
+    /// Returns the duration until the next timer is scheduled to fire, if
+    /// any timer is currently registered on any worker.
+    ///
+    /// This is the minimum across every worker's own next-expiration
+    /// snapshot, not just whichever worker's park loop happened to run
+    /// most recently -- `RuntimeMetrics` is meant to be read from any
+    /// thread, so it can't rely on the thread-bound helper the time
+    /// driver's own park loop uses internally.
+    pub fn next_timer_expiration(&self) -> Option<Duration> {
+        next_expiration_across_workers(self.handle.driver())
+    }
+}
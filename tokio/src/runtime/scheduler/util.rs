@@ -3,6 +3,7 @@ cfg_rt_and_time! {
         use crate::runtime::{scheduler::driver};
         use crate::runtime::time::Context2;
         use crate::runtime::time::EntryHandle;
+        use crate::runtime::time::wheel::entry::TimerMetrics;
         use crate::util::WakeList;
         use std::time::Duration;
 
@@ -77,10 +78,16 @@ cfg_rt_and_time! {
                     let mut waker_list = WakeList::new();
                     let wheel = &mut time_context.wheel;
                     let canc_tx = &time_context.canc_tx;
+                    let metrics = &time_context.metrics;
                     while let Some(hdl) = inject.pop() {
                         match unsafe { wheel.insert(hdl.clone(), canc_tx.clone()) } {
-                            Insert::Success => {}
+                            Insert::Success => {
+                                hdl.set_owner_wheel(wheel as *const _);
+                                hdl.set_owner_metrics(metrics as *const _);
+                                metrics.record_registered();
+                            }
                             Insert::Elapsed => {
+                                metrics.record_premature();
                                 let waker = hdl.take_waker_unregistered();
                                 match waker {
                                     Some(waker) if waker_list.can_push() => {
@@ -109,6 +116,8 @@ cfg_rt_and_time! {
 
         pub(crate) fn remove_cancelled_timers() {
             with_context2(|time_context| {
+                // Genuinely cross-thread cancellations still arrive one at
+                // a time over the channel.
                 for hdl in time_context.canc_rx.recv_all() {
                     let is_registered = hdl.is_registered();
                     let is_pending = hdl.is_pending();
@@ -116,11 +125,31 @@ cfg_rt_and_time! {
                         unsafe {
                             time_context.wheel.remove(hdl);
                         }
+                        time_context.metrics.record_cancelled_processed();
+                    }
+                }
+
+                // Same-thread cancellations were linked onto the
+                // lock-free cancel stack by `Timer::cancel`; drain the
+                // whole thing in one pass rather than per-timer.
+                for hdl in time_context.cancel_stack.drain() {
+                    if hdl.is_cancelled() {
+                        unsafe {
+                            time_context.wheel.remove(hdl);
+                        }
+                        time_context.metrics.record_cancelled_processed();
                     }
                 }
             });
         }
 
+        /// Returns the duration until *this worker's* next timer, updating
+        /// its own `TimerMetrics::next_expiration_tick` snapshot as a side
+        /// effect. Only ever meant to be called from the owning worker's
+        /// own park loop -- `with_context2` panics off that thread, and
+        /// this only ever reports this one worker's wheel. For the
+        /// any-thread, whole-runtime `RuntimeMetrics::next_timer_expiration`,
+        /// see `next_expiration_across_workers` instead.
         pub(crate) fn next_expiration_time(
             drv_hdl: &driver::Handle,
         ) -> Option<Duration> {
@@ -134,7 +163,9 @@ cfg_rt_and_time! {
                 let time_source = time_hdl.time_source();
 
                 with_context2(|time_context| {
-                    time_context.wheel.next_expiration_time().map(|tick| {
+                    let tick = time_context.wheel.next_expiration_time();
+                    time_context.metrics.set_next_expiration(tick);
+                    tick.map(|tick| {
                         let now = time_source.now(clock);
                         time_source.tick_to_duration(tick.saturating_sub(now))
                     })
@@ -142,6 +173,72 @@ cfg_rt_and_time! {
             })
         }
 
+        /// Iterates every worker's own `TimerMetrics`, so the aggregate
+        /// `RuntimeMetrics` accessors below reflect the whole runtime
+        /// instead of a single worker -- or, before metrics were scoped
+        /// per-worker, a single process-wide static shared by every
+        /// `Runtime` in the process.
+        fn per_worker_timer_metrics(
+            drv_hdl: &driver::Handle,
+        ) -> impl Iterator<Item = &TimerMetrics> {
+            drv_hdl.scheduler_handle().time_contexts().map(|ctx| &ctx.metrics)
+        }
+
+        /// Total timers currently registered with a wheel, across every
+        /// worker.
+        pub(crate) fn active_timers_count(drv_hdl: &driver::Handle) -> usize {
+            per_worker_timer_metrics(drv_hdl)
+                .map(TimerMetrics::active_timers_count)
+                .sum()
+        }
+
+        /// Total timers whose deadline had already passed when a worker
+        /// inserted them into its wheel, across every worker.
+        pub(crate) fn premature_timers_total(drv_hdl: &driver::Handle) -> u64 {
+            per_worker_timer_metrics(drv_hdl)
+                .map(|m| m.premature_timers_total() as u64)
+                .sum()
+        }
+
+        /// Total cancelled timers removed from a wheel, across every
+        /// worker.
+        pub(crate) fn cancelled_timers_total(drv_hdl: &driver::Handle) -> u64 {
+            per_worker_timer_metrics(drv_hdl)
+                .map(|m| m.cancelled_timers_total() as u64)
+                .sum()
+        }
+
+        /// Returns the duration until the earliest timer any worker has
+        /// scheduled to fire, or `None` if no worker has one registered.
+        ///
+        /// Deliberately doesn't call `with_context2`/`with_scheduler` the
+        /// way `next_expiration_time` does: those are thread-bound
+        /// helpers written for the time driver's own per-worker park
+        /// loop, so calling them from arbitrary code -- the whole point
+        /// of `RuntimeMetrics` being an any-thread API -- panics unless
+        /// the caller happens to be on a worker thread, and even then
+        /// only reports that one worker's wheel. This instead reads every
+        /// worker's own cached `next_timer_expiration` snapshot (each
+        /// kept current by that worker's own `next_expiration_time` call)
+        /// and takes the minimum across all of them, which is the soonest
+        /// any worker will actually wake for -- not whichever worker's
+        /// park loop happened to run last.
+        pub(crate) fn next_expiration_across_workers(
+            drv_hdl: &driver::Handle,
+        ) -> Option<Duration> {
+            drv_hdl.with_time(|maybe_time_hdl| {
+                let time_hdl = maybe_time_hdl?;
+                let clock = drv_hdl.clock();
+                let time_source = time_hdl.time_source();
+                let now = time_source.now(clock);
+
+                per_worker_timer_metrics(drv_hdl)
+                    .filter_map(TimerMetrics::next_timer_expiration)
+                    .min()
+                    .map(|tick| time_source.tick_to_duration(tick.saturating_sub(now)))
+            })
+        }
+
         cfg_test_util! {
             pub(crate) fn pre_auto_advance(
                 drv_hdl: &driver::Handle,
@@ -245,11 +342,19 @@ cfg_rt_and_time! {
                     }
                 }
 
+                for hdl in time_context.cancel_stack.drain() {
+                    if hdl.is_cancelled() {
+                        unsafe {
+                            time_context.wheel.remove(hdl);
+                        }
+                    }
+                }
 
                 for hdl in inject {
                     match unsafe { time_context.wheel.insert(hdl.clone(), time_context.canc_tx.clone()) } {
                         Insert::Success => {}
                         Insert::Elapsed => {
+                            time_context.metrics.record_premature();
                             if let Some(waker) = hdl.take_waker_unregistered() {
                                 waker.wake();
                             }
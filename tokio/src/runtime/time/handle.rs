@@ -1,5 +1,7 @@
+use crate::runtime::time::wheel::entry::MAX_SAFE_MILLIS_DURATION;
 use crate::runtime::time::TimeSource;
 use std::fmt;
+use std::time::Duration;
 
 cfg_test_util! {
     use crate::loom::sync::Arc;
@@ -18,14 +20,44 @@ pub(crate) struct Handle {
     // and pausing the clock is restricted to a single-threaded runtime.
     #[cfg(feature = "test-util")]
     pub(super) did_wake: Arc<AtomicBool>,
+
+    // Granularity, in wheel ticks, that deadlines are rounded up to before
+    // being scheduled. Timers whose rounded deadlines land on the same tick
+    // wake together, trading a bit of timing precision for fewer wheel
+    // insertions/cancellations and a shared wakeup under high timer counts.
+    // Configured via `Builder::timer_slack`; `0` (the default) disables
+    // coalescing and preserves exact deadlines.
+    pub(super) timer_slack: u64,
 }
 
 impl Handle {
+    /// Constructs the time driver's handle.
+    ///
+    /// `timer_slack` is already in wheel ticks (see
+    /// `TimeSource::duration_to_ticks`) -- this is the one place
+    /// `Builder::timer_slack` actually lands on the `Handle` that
+    /// `deadline_to_tick` reads via `timer_slack()`; without threading it
+    /// through here, the value would sit on the `Builder` and never
+    /// reach the driver.
+    pub(crate) fn new(time_source: TimeSource, timer_slack: u64) -> Self {
+        Handle {
+            time_source,
+            #[cfg(feature = "test-util")]
+            did_wake: Arc::new(AtomicBool::new(false)),
+            timer_slack,
+        }
+    }
+
     /// Returns the time source associated with this handle.
     pub(crate) fn time_source(&self) -> &TimeSource {
         &self.time_source
     }
 
+    /// Returns the configured timer slack, in wheel ticks.
+    pub(crate) fn timer_slack(&self) -> u64 {
+        self.timer_slack
+    }
+
     /// Track that the driver is being unparked
     pub(crate) fn unpark(&self) {
         #[cfg(feature = "test-util")]
@@ -72,3 +104,38 @@ impl fmt::Debug for Handle {
         write!(f, "Handle")
     }
 }
+
+impl TimeSource {
+    /// Converts a `Builder::timer_slack` granularity into wheel ticks.
+    ///
+    /// Wheel ticks are milliseconds, so this is just a saturating
+    /// millisecond truncation, capped at `MAX_SAFE_MILLIS_DURATION` the
+    /// same way a deadline's tick is -- a slack this large would never
+    /// usefully group anything anyway, and saturating keeps an
+    /// extreme/accidental `Duration` (e.g. `Duration::MAX`) from
+    /// wrapping into a small, surprising tick count.
+    pub(crate) fn duration_to_ticks(slack: Duration) -> u64 {
+        u64::try_from(slack.as_millis())
+            .unwrap_or(MAX_SAFE_MILLIS_DURATION)
+            .min(MAX_SAFE_MILLIS_DURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_to_ticks_is_millis() {
+        assert_eq!(TimeSource::duration_to_ticks(Duration::from_millis(5)), 5);
+        assert_eq!(TimeSource::duration_to_ticks(Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn duration_to_ticks_saturates_on_overflow() {
+        assert_eq!(
+            TimeSource::duration_to_ticks(Duration::MAX),
+            MAX_SAFE_MILLIS_DURATION
+        );
+    }
+}
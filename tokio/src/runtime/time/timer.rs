@@ -1,4 +1,5 @@
-use super::wheel::EntryHandle;
+use super::wheel::entry::CancelOutcome;
+use super::wheel::{CancelStack, EntryHandle};
 use crate::{runtime::time::Wheel, time::Instant, util::error::RUNTIME_SHUTTING_DOWN_ERROR};
 use std::{
     pin::Pin,
@@ -55,6 +56,9 @@ impl Timer {
             let hdl = EntryHandle::new(deadline, cx.waker());
             if let Some((wheel, tx)) = maybe_wheel {
                 if unsafe { wheel.insert(hdl.clone(), tx) } {
+                    hdl.set_owner_wheel(wheel as *const _);
+                    hdl.set_owner_metrics(wheel.metrics() as *const _);
+                    wheel.metrics().record_registered();
                     this.entry = Some(hdl);
                     Poll::Pending
                 } else {
@@ -81,9 +85,132 @@ impl Timer {
 
     pub(crate) fn cancel(self: Pin<&mut Self>) {
         if let Some(entry) = self.get_mut().entry.take() {
-            entry.cancel();
+            cancel_entry(entry);
         }
     }
+
+    /// Reschedules this timer to a new `deadline`, reusing the existing
+    /// wheel entry instead of cancelling and re-registering a fresh one.
+    ///
+    /// This only takes the fast path while the entry is still
+    /// `STATE_REGISTERED` (registered, but not yet fired): the wheel
+    /// moves it to the slot for the new deadline in place. If the entry
+    /// has already fired, is mid-delivery, or was never registered, this
+    /// just updates the stored deadline and lets the next
+    /// `poll_elapsed`/`register` register a fresh entry for it.
+    pub(crate) fn reset(self: Pin<&mut Self>, deadline: Instant) {
+        let this = self.get_mut();
+        this.deadline = deadline;
+
+        let Some(entry) = this.entry.as_ref() else {
+            return;
+        };
+
+        if !entry.is_registered() {
+            // Either still in flight to a remote wheel
+            // (`STATE_UNREGISTERED`, pushed by `push_from_remote` but not
+            // yet processed by `insert_inject_timers`) or already fired.
+            // The in-flight case must be cancelled now via
+            // `cancel_entry`, or that stale entry would go on to register
+            // and fire at the *old* deadline once the remote wheel
+            // catches up to it, alongside the fresh entry the next
+            // `register` creates for the new deadline. The already-fired
+            // case makes `cancel_entry` a no-op, same as before.
+            if let Some(entry) = this.entry.take() {
+                cancel_entry(entry);
+            }
+            return;
+        }
+
+        let new_when = deadline_to_tick(deadline);
+        let entry = entry.clone();
+
+        // `with_current_wheel` only tells us whether *this* thread has a
+        // local wheel, not whether it's the one `entry` is actually
+        // linked into -- under work-stealing, the task owning this
+        // `Timer` can be polled (and so call `reset`) on a different
+        // worker than the one that registered it. Taking the in-place
+        // `reschedule` fast path against the wrong wheel would corrupt
+        // that wheel's list while leaving `entry` still really linked in
+        // its actual owner's wheel, so only take it once the owner
+        // check confirms a match; any mismatch (or no local wheel at
+        // all) falls back to cancelling the stale entry and letting the
+        // next `register`/`poll_elapsed` create a fresh one, same as the
+        // not-yet-registered branch above.
+        let needs_cancel = with_current_wheel(|maybe_wheel| match maybe_wheel {
+            Some((wheel, _tx)) if entry.is_owned_by_wheel(wheel as *const _) => {
+                unsafe { wheel.reschedule(entry, new_when) };
+                None
+            }
+            _ => Some(entry),
+        });
+
+        if let Some(entry) = needs_cancel {
+            this.entry.take();
+            cancel_entry(entry);
+        }
+    }
+}
+
+/// Marks `entry` cancelled and, if it was actually linked into a wheel,
+/// unlinks it — via the owning worker's local `CancelStack` if we're
+/// running on its thread, or the cross-thread `cancel_tx` channel
+/// otherwise. Shared by `Timer::cancel` and `Timer::reset`'s
+/// not-yet-registered path, since both need the exact same handling once
+/// they have an `EntryHandle` they no longer want to keep.
+fn cancel_entry(entry: EntryHandle) {
+    match entry.mark_cancelled() {
+        CancelOutcome::WasRegistered => {
+            // `with_current_cancel_stack` only tells us whether *this*
+            // thread happens to be a worker with a local cancel stack,
+            // not whether it's the owning worker for `entry` specifically
+            // -- under work-stealing, the task dropping/cancelling a
+            // `Timer` can run on a different worker than the one that
+            // registered it. Pushing onto the wrong worker's stack would
+            // corrupt that worker's wheel when it later tries to unlink
+            // an entry it never actually inserted, while leaving `entry`
+            // still really linked in its real owner's wheel. So check
+            // the owning wheel's identity (via the worker's local wheel,
+            // which is 1:1 with its cancel stack) before taking the
+            // local fast path at all.
+            let handled_locally = with_current_wheel(|maybe_wheel| {
+                let Some((wheel, _tx)) = maybe_wheel else {
+                    return false;
+                };
+
+                if !entry.is_owned_by_wheel(wheel as *const _) {
+                    return false;
+                }
+
+                with_current_cancel_stack(|maybe_stack| match maybe_stack {
+                    Some(stack) => {
+                        // On the owning worker's thread: batch this
+                        // unlink in with every other cancellation since
+                        // the last drain instead of paying a channel
+                        // send.
+                        unsafe { stack.push(entry.clone()) };
+                        true
+                    }
+                    None => false,
+                })
+            });
+
+            if !handled_locally {
+                // Genuinely cross-thread (or a same-thread wheel that
+                // isn't this entry's own): the owning worker drains this
+                // channel on its own schedule.
+                entry.send_to_cancel_channel();
+            }
+        }
+        CancelOutcome::WasUnregistered => {
+            // Still in flight to a remote wheel; flipping the state to
+            // `STATE_CANCELLED` is enough on its own. There's nothing
+            // linked anywhere yet to unlink — the remote wheel's
+            // `insert` will see the cancelled state and skip registering
+            // it (`Insert::Cancelling`).
+        }
+        CancelOutcome::AlreadyGone => {}
+    }
 }
 
 fn with_current_wheel<F, R>(f: F) -> R
@@ -110,6 +237,34 @@ where
     })
 }
 
+/// Runs `f` against the current worker's `CancelStack`, or `None` if this
+/// thread isn't a worker with one (e.g. cancelling from outside the
+/// runtime). On a worker thread this is that worker's own stack, whether
+/// or not that worker owns the entry being cancelled.
+fn with_current_cancel_stack<F, R>(f: F) -> R
+where
+    F: FnOnce(Option<&CancelStack>) -> R,
+{
+    #[cfg(feature = "rt")]
+    use crate::runtime::scheduler::Context::CurrentThread;
+    #[cfg(feature = "rt-multi-thread")]
+    use crate::runtime::scheduler::Context::MultiThread;
+
+    #[cfg(not(feature = "rt"))]
+    let _ = f;
+
+    #[cfg(not(feature = "rt"))]
+    panic!("Tokio runtime is not enabled, cannot access the current wheel");
+
+    #[cfg(feature = "rt")]
+    crate::runtime::context::with_scheduler(|maybe_cx| match maybe_cx {
+        Some(CurrentThread(cx)) => cx.with_cancel_stack(f),
+        #[cfg(feature = "rt-multi-thread")]
+        Some(MultiThread(cx)) => cx.with_cancel_stack(f),
+        None => f(None),
+    })
+}
+
 fn push_from_remote(hdl: EntryHandle) {
     #[cfg(feature = "rt")]
     use crate::runtime::scheduler::Handle::CurrentThread;
@@ -138,5 +293,54 @@ fn deadline_to_tick(deadline: Instant) -> u64 {
         panic!("{RUNTIME_SHUTTING_DOWN_ERROR}");
     }
 
-    hdl.time_source().deadline_to_tick(deadline)
+    let tick = hdl.time_source().deadline_to_tick(deadline);
+    round_up_to_slack(tick, hdl.timer_slack())
+}
+
+/// Rounds `tick` up to the next multiple of `slack`, deliberately
+/// clustering nearby deadlines into the same wheel slot so they fire
+/// (and wake) together. Never rounds below the originally requested
+/// tick. A `slack` of `0` or `1` disables coalescing and returns `tick`
+/// unchanged.
+fn round_up_to_slack(tick: u64, slack: u64) -> u64 {
+    if slack <= 1 {
+        return tick;
+    }
+
+    match tick.checked_add(slack - 1) {
+        Some(rounded) => (rounded / slack) * slack,
+        // Would overflow; leave the tick unrounded rather than wrap.
+        None => tick,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_up_to_slack;
+
+    #[test]
+    fn zero_and_one_disable_coalescing() {
+        assert_eq!(round_up_to_slack(1234, 0), 1234);
+        assert_eq!(round_up_to_slack(1234, 1), 1234);
+    }
+
+    #[test]
+    fn rounds_up_to_the_next_multiple() {
+        assert_eq!(round_up_to_slack(101, 10), 110);
+        assert_eq!(round_up_to_slack(100, 10), 100);
+        assert_eq!(round_up_to_slack(0, 10), 0);
+    }
+
+    #[test]
+    fn never_rounds_below_the_requested_tick() {
+        for tick in 0..25 {
+            assert!(round_up_to_slack(tick, 10) >= tick);
+        }
+    }
+
+    #[test]
+    fn saturates_instead_of_overflowing() {
+        assert_eq!(round_up_to_slack(u64::MAX, 10), u64::MAX);
+        assert_eq!(round_up_to_slack(u64::MAX - 1, 10), u64::MAX - 1);
+    }
 }
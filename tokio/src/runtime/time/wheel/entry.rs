@@ -1,6 +1,7 @@
 use crate::loom::cell::UnsafeCell;
-use crate::loom::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use crate::loom::sync::atomic::{AtomicPtr, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use crate::loom::sync::Mutex;
+use crate::runtime::time::{Insert, Wheel};
 use crate::{sync::AtomicWaker, util::linked_list};
 use std::sync::Arc;
 use std::{ptr::NonNull, sync::mpsc, task::Waker};
@@ -11,15 +12,111 @@ pub(crate) const STATE_UNREGISTERED: u8 = 0;
 pub(crate) const STATE_REGISTERED: u8 = 1;
 pub(crate) const STATE_PENDING: u8 = 2;
 pub(crate) const STATE_PREMATURE: u8 = 3;
+pub(crate) const STATE_CANCELLED: u8 = 4;
 pub(crate) const MAX_SAFE_MILLIS_DURATION: u64 = u64::MAX - 1;
 
+/// Outcome of `Entry::mark_cancelled`, telling the caller what (if
+/// anything) it still needs to unlink.
+pub(crate) enum CancelOutcome {
+    /// Was linked into a wheel (`STATE_REGISTERED`). The caller must
+    /// unlink it — via the owning worker's `CancelStack` if local, or
+    /// `send_to_cancel_channel` otherwise.
+    WasRegistered,
+    /// Was still in flight to a remote wheel (`STATE_UNREGISTERED`), not
+    /// yet linked anywhere. Nothing to unlink now; the remote wheel's
+    /// `insert` will observe `STATE_CANCELLED` and skip registering it
+    /// (see `Insert::Cancelling`).
+    WasUnregistered,
+    /// Already firing, fired, or already cancelled: nothing to do.
+    AlreadyGone,
+}
+
+/// Counters backing `RuntimeMetrics`' timer-pressure accessors
+/// (`active_timers_count`, `premature_timers_total`,
+/// `next_timer_expiration`).
+///
+/// Owned per-worker -- alongside `wheel`/`canc_tx` on `Context2`, and
+/// reachable as `Wheel::metrics` for call sites that only have a
+/// `&Wheel` handy -- rather than as a single process-wide `static`. A
+/// shared static meant any two independent `Runtime`s in the same
+/// process read each other's counts through `RuntimeMetrics::*`, even
+/// though those accessors are documented as reflecting *this* runtime's
+/// time driver. `RuntimeMetrics` now aggregates across every worker's
+/// own instance (see `scheduler::util::time::per_worker_timer_metrics`)
+/// instead of reading a single shared one.
+pub(crate) struct TimerMetrics {
+    /// Number of entries inserted into a wheel whose last handle has not
+    /// yet been dropped.
+    registered: AtomicUsize,
+    /// Total entries whose deadline had already passed when a worker
+    /// inserted them (the `Insert::Elapsed` paths in
+    /// `insert_inject_timers`, `shutdown_local_timers` and
+    /// `Wheel::reschedule`).
+    premature_total: AtomicUsize,
+    /// Total entries removed from a wheel via the cancellation drain.
+    cancelled_processed_total: AtomicUsize,
+    /// Tick of the next timer due to fire, or `u64::MAX` if none.
+    next_expiration_tick: AtomicU64,
+}
+
+impl TimerMetrics {
+    pub(crate) const fn new() -> Self {
+        Self {
+            registered: AtomicUsize::new(0),
+            premature_total: AtomicUsize::new(0),
+            cancelled_processed_total: AtomicUsize::new(0),
+            next_expiration_tick: AtomicU64::new(u64::MAX),
+        }
+    }
+
+    pub(crate) fn active_timers_count(&self) -> usize {
+        self.registered.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn premature_timers_total(&self) -> usize {
+        self.premature_total.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn cancelled_timers_total(&self) -> usize {
+        self.cancelled_processed_total.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn next_timer_expiration(&self) -> Option<u64> {
+        match self.next_expiration_tick.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            tick => Some(tick),
+        }
+    }
+
+    pub(crate) fn set_next_expiration(&self, tick: Option<u64>) {
+        self.next_expiration_tick
+            .store(tick.unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_registered(&self) {
+        self.registered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_deregistered(&self) {
+        self.registered.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_premature(&self) {
+        self.premature_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_cancelled_processed(&self) {
+        self.cancelled_processed_total.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 pub(crate) struct Entry {
     /// Intrusive list pointers.
     pointers: linked_list::Pointers<Entry>,
 
     state: AtomicU8,
 
-    when: u64,
+    when: UnsafeCell<u64>,
 
     cancel_tx: Mutex<Option<mpsc::Sender<Handle>>>,
 
@@ -27,6 +124,54 @@ pub(crate) struct Entry {
     waker: AtomicWaker,
 
     handle: Handle,
+
+    /// Intrusive singly-linked stack link used only while this entry sits
+    /// on a `CancelStack` awaiting removal from the wheel. Kept separate
+    /// from `pointers` (the wheel's own doubly-linked list link) rather
+    /// than reusing it: an entry on the cancel stack is still linked into
+    /// its wheel's slot list until the drain unlinks it, so both links
+    /// are in use at once. The `STATE_REGISTERED` -> `STATE_CANCELLED`
+    /// CAS in `mark_cancelled` keeps such an entry from also being moved
+    /// to `STATE_PENDING` by `poll`.
+    cancel_next: UnsafeCell<*mut Entry>,
+
+    /// Raw pointer to this entry's own `refs` `Arc` allocation, stashed by
+    /// `CancelStack::push` via `Arc::into_raw` while the entry sits on
+    /// the cancel stack. `CancelDrain::next` reconstructs the exact `Arc`
+    /// `push` forgot via `Arc::from_raw`, rather than
+    /// `entry.handle.refs.clone()`, which would bump the strong count a
+    /// second time with nothing ever decrementing it again. Null outside
+    /// the window between `push` and the matching `drain` iteration.
+    cancel_refs: UnsafeCell<*const AtomicUsize>,
+
+    /// Raw identity of the `Wheel` this entry is currently linked into,
+    /// recorded once by `Timer::register`/`insert_inject_timers` right
+    /// after a successful insert. `0` means "not yet linked anywhere".
+    ///
+    /// `with_current_wheel`/`with_current_cancel_stack` only tell you
+    /// whether the *calling* thread happens to have a local wheel, not
+    /// whether it's *this entry's* wheel -- under work-stealing, the task
+    /// owning a `Timer` can be polled or dropped on a different worker
+    /// than the one that registered it. `cancel_entry`/`Timer::reset`
+    /// compare against this before taking the same-thread fast path, so a
+    /// wrong-worker poll falls back to the cross-thread path instead of
+    /// unlinking the entry from (or relinking it into) a wheel it was
+    /// never actually inserted into.
+    owner_wheel: AtomicUsize,
+
+    /// Raw identity of the `TimerMetrics` this entry is counted against,
+    /// recorded at the same time and place as `owner_wheel`. `0` means
+    /// "never successfully inserted", i.e. never counted as registered in
+    /// the first place.
+    ///
+    /// Metrics are scoped per-worker (see `TimerMetrics`'s doc comment),
+    /// so unlike `owner_wheel` -- which is only ever compared, never
+    /// dereferenced -- `Handle::drop` actually has to read through this
+    /// pointer to credit the deregistration to the right worker. That's
+    /// sound under the same assumption `owner_wheel` already relies on:
+    /// a worker's `TimerMetrics` outlives every `Handle` that could still
+    /// be crediting it.
+    owner_metrics: AtomicUsize,
 }
 
 generate_addr_of_methods! {
@@ -67,12 +212,62 @@ impl Entry {
         *lock = Some(cancel_tx);
     }
 
+    /// Returns a clone of the currently-set cancel channel, if any,
+    /// without consuming it (unlike `send_to_cancel_channel`). Used by
+    /// `Wheel::reschedule` to re-insert a still-registered entry under a
+    /// new deadline while keeping the same channel it was already
+    /// registered with.
+    pub(crate) fn cancel_tx(&self) -> Option<mpsc::Sender<Handle>> {
+        self.cancel_tx.lock().clone()
+    }
+
     pub(crate) fn handle(&self) -> &Handle {
         &self.handle
     }
 
     pub(crate) fn when(&self) -> u64 {
-        self.when
+        self.when.with(|ptr| unsafe { *ptr })
+    }
+
+    /// Updates the tick at which this entry should fire.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the entry is not concurrently linked into
+    /// any `Wheel` level/slot list while this runs (e.g. because the
+    /// caller has just removed it as part of a reschedule, or because it
+    /// has not yet been inserted). The wheel derives an entry's slot from
+    /// `when`, so mutating it while linked would corrupt the wheel's
+    /// indexing.
+    pub(crate) unsafe fn set_when(&self, when: u64) {
+        self.when.with_mut(|ptr| *ptr = when);
+    }
+
+    /// Records `wheel` as the one this entry is linked into. Called once,
+    /// right after a successful insert.
+    pub(crate) fn set_owner_wheel(&self, wheel: *const Wheel) {
+        self.owner_wheel.store(wheel as usize, Ordering::Release);
+    }
+
+    /// Whether `wheel` is actually the wheel this entry is linked into.
+    pub(crate) fn is_owned_by_wheel(&self, wheel: *const Wheel) -> bool {
+        self.owner_wheel.load(Ordering::Acquire) == wheel as usize
+    }
+
+    /// Records `metrics` as the per-worker `TimerMetrics` this entry is
+    /// counted against. Called alongside `set_owner_wheel`, right after a
+    /// successful insert.
+    pub(crate) fn set_owner_metrics(&self, metrics: *const TimerMetrics) {
+        self.owner_metrics.store(metrics as usize, Ordering::Release);
+    }
+
+    /// The `TimerMetrics` this entry was counted against at insert time,
+    /// if it was ever successfully inserted.
+    fn owner_metrics(&self) -> Option<*const TimerMetrics> {
+        match self.owner_metrics.load(Ordering::Acquire) {
+            0 => None,
+            ptr => Some(ptr as *const TimerMetrics),
+        }
     }
 
     pub(crate) fn transition_to_registered(&self) {
@@ -81,12 +276,24 @@ impl Entry {
     }
 
     pub(crate) fn transition_to_pending(&self, not_after: u64) -> Result<(), u64> {
-        if self.when > not_after {
-            return Err(self.when);
+        let when = self.when();
+        if when > not_after {
+            return Err(when);
+        }
+        // A CAS, not a swap: `mark_cancelled()` can flip `STATE_REGISTERED` to
+        // `STATE_CANCELLED` from any thread concurrently with `poll`
+        // reaching this entry. `is_cancelled()` should already have
+        // steered `poll` away from entries on the cancel stack, but if we
+        // still lose that race here, treat it the same as "nothing to
+        // fire" instead of asserting.
+        match self
+            .state
+            .compare_exchange(STATE_REGISTERED, STATE_PENDING, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            Ok(_) => Ok(()),
+            Err(STATE_CANCELLED) => Err(when),
+            Err(other) => unreachable!("Entry not registered (state = {other})"),
         }
-        let old = self.state.swap(STATE_PENDING, Ordering::Relaxed);
-        assert_eq!(old, STATE_REGISTERED, "Entry not registered");
-        Ok(())
     }
 
     pub(crate) fn fire(&self) {
@@ -101,6 +308,12 @@ impl Entry {
         self.waker.wake();
         let old = self.state.swap(STATE_PREMATURE, Ordering::Release);
         assert_eq!(old, STATE_UNREGISTERED, "Entry state changed unexpectedly");
+        // Deliberately no `TimerMetrics::record_premature` here: an entry
+        // that never registered never picked an `owner_metrics`, so there
+        // is no single worker to credit from inside `Entry` itself. The
+        // `Insert::Elapsed` call sites (`insert_inject_timers`,
+        // `shutdown_local_timers`, `Wheel::reschedule`) already know which worker's wheel just
+        // observed the elapsed insert, so they record it directly.
     }
 
     pub(crate) fn is_elapsed(&self) -> bool {
@@ -120,14 +333,157 @@ impl Entry {
         self.state.fetch_or(0, Ordering::Relaxed) == STATE_PREMATURE
     }
 
-    pub(crate) fn cancel(&self) {
-        if self.is_registered() {
-            if let Some(tx) = self.cancel_tx.lock().take() {
-                tx.send(self.handle.clone())
-                    .expect("Failed to send cancel message");
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.state.fetch_or(0, Ordering::Relaxed) == STATE_CANCELLED
+    }
+
+    /// Attempts to mark this entry cancelled, from either
+    /// `STATE_REGISTERED` or `STATE_UNREGISTERED`.
+    ///
+    /// `STATE_UNREGISTERED` is included because an entry can still be in
+    /// flight to a remote wheel's inject queue (pushed by
+    /// `push_from_remote` but not yet processed by
+    /// `insert_inject_timers`) when it's cancelled or rescheduled; if we
+    /// only matched `STATE_REGISTERED` here, that cancellation would
+    /// silently no-op, and the in-flight entry would go on to register
+    /// and fire at its old deadline once the remote wheel caught up to
+    /// it. See `CancelOutcome::WasUnregistered` for how that race is
+    /// actually closed.
+    pub(crate) fn mark_cancelled(&self) -> CancelOutcome {
+        loop {
+            let current = self.state.load(Ordering::Acquire);
+            let outcome = match current {
+                STATE_REGISTERED => CancelOutcome::WasRegistered,
+                STATE_UNREGISTERED => CancelOutcome::WasUnregistered,
+                _ => return CancelOutcome::AlreadyGone,
+            };
+            match self.state.compare_exchange(
+                current,
+                STATE_CANCELLED,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return outcome,
+                Err(_) => continue,
             }
         }
     }
+
+    /// Sends this entry over its `cancel_tx` channel, for the genuinely
+    /// cross-thread case where the cancelling thread has no local
+    /// `CancelStack` for the entry's owning wheel.
+    pub(crate) fn send_to_cancel_channel(&self) {
+        if let Some(tx) = self.cancel_tx.lock().take() {
+            tx.send(self.handle.clone())
+                .expect("Failed to send cancel message");
+        }
+    }
+}
+
+/// Lock-free (Treiber) stack of cancelled entries awaiting removal from a
+/// wheel, used by the owning worker to batch-drain every cancellation
+/// since the last drain in one pass instead of paying a channel send per
+/// dropped `Timer`.
+///
+/// Only entries cancelled on the thread that owns their wheel are ever
+/// pushed here; a `Timer` cancelled from elsewhere still goes through
+/// `cancel_tx`, since a non-owning thread has no safe way to unlink the
+/// entry from a wheel list it doesn't have exclusive access to.
+pub(crate) struct CancelStack {
+    head: AtomicPtr<Entry>,
+}
+
+impl CancelStack {
+    pub(crate) const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(std::ptr::null_mut()),
+        }
+    }
+
+    /// Pushes `handle` onto the stack.
+    ///
+    /// # Safety
+    ///
+    /// `handle`'s entry must have just won the `STATE_REGISTERED` ->
+    /// `STATE_CANCELLED` CAS in `mark_cancelled` (so `poll` can no longer
+    /// move it to `STATE_PENDING`; it stays linked in the wheel's own
+    /// list until the drain removes it), and must not already be linked
+    /// onto this stack.
+    pub(crate) unsafe fn push(&self, handle: Handle) {
+        let raw = handle.entry.as_ptr();
+
+        // `handle`'s ref-count contribution transfers to the stack.
+        // `Handle` implements `Drop`, so its fields can't be moved out of
+        // it directly; `ManuallyDrop` lets us read `refs` out without
+        // running `Handle::drop`, which would otherwise immediately
+        // consider this the entry's last reference and free it
+        // synchronously while it's still linked here -- the
+        // use-after-free this stack used to have.
+        //
+        // `refs` itself is stashed via `Arc::into_raw` (which does not
+        // decrement the strong count) rather than cloned: cloning would
+        // add a *second*, independent strong-count contribution that
+        // nothing ever decrements again, since `CancelDrain::next`
+        // reconstructs this exact `Arc` rather than cloning another one.
+        // `Arc::from_raw` in `CancelDrain::next` is what finally balances
+        // this `Arc::into_raw`.
+        let handle = std::mem::ManuallyDrop::new(handle);
+        let refs = unsafe { std::ptr::read(&handle.refs) };
+        unsafe {
+            (*raw).cancel_refs.with_mut(|ptr| *ptr = Arc::into_raw(refs));
+        }
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            unsafe {
+                (*raw).cancel_next.with_mut(|next| *next = head);
+            }
+            match self
+                .head
+                .compare_exchange_weak(head, raw, Ordering::AcqRel, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    /// Atomically takes the whole stack, returning the cancelled entries
+    /// in most-recently-cancelled-first order.
+    pub(crate) fn drain(&self) -> CancelDrain {
+        let head = self.head.swap(std::ptr::null_mut(), Ordering::Acquire);
+        CancelDrain { next: head }
+    }
+}
+
+unsafe impl Send for CancelStack {}
+unsafe impl Sync for CancelStack {}
+
+pub(crate) struct CancelDrain {
+    next: *mut Entry,
+}
+
+impl Iterator for CancelDrain {
+    type Item = Handle;
+
+    fn next(&mut self) -> Option<Handle> {
+        let ptr = NonNull::new(self.next)?;
+        let entry = unsafe { ptr.as_ref() };
+        self.next = entry.cancel_next.with(|next| unsafe { *next });
+
+        // Reconstruct the exact `Arc` that `push` stashed via
+        // `Arc::into_raw`, rather than `entry.handle.refs.clone()`:
+        // cloning would bump the strong count a *second* time with
+        // nothing ever decrementing it again, since the `Handle` `push`
+        // forgot already carries its own un-reclaimed contribution to
+        // `refs`. `Arc::from_raw` here is exactly what balances that
+        // earlier `Arc::into_raw`.
+        let refs = entry
+            .cancel_refs
+            .with(|ptr| unsafe { Arc::from_raw(*ptr) });
+
+        Some(Handle { refs, entry: ptr })
+    }
 }
 
 pub(crate) struct Handle {
@@ -161,6 +517,13 @@ impl Drop for Handle {
         // `refs == 2` means this is the last handle except another one
         // in the entry itself.
         if self.refs.fetch_sub(1, Ordering::Release) == 2 {
+            if let Some(metrics) = self.owner_metrics() {
+                // Safety: `owner_metrics` is set once, at insert-success
+                // time, to the worker's `TimerMetrics` -- the same
+                // worker-lifetime assumption `owner_wheel` already
+                // relies on for its own raw pointer.
+                unsafe { (*metrics).record_deregistered() };
+            }
             unsafe {
                 std::ptr::drop_in_place(self.entry.as_ptr());
             }
@@ -176,6 +539,13 @@ impl std::ops::Deref for Handle {
     }
 }
 
+/// Reads out the raw entry pointer without taking ownership of it. Note
+/// that this does *not* suppress `handle`'s drop: only the `Copy` `entry`
+/// field is copied out, so `handle`'s `refs` `Arc` is still dropped
+/// normally at the end of this call. Callers that mean to transfer
+/// `handle`'s ref-count into something else (e.g. an intrusive list or
+/// stack) must `mem::forget(handle)` themselves; don't reach for this
+/// conversion for that.
 impl From<Handle> for NonNull<Entry> {
     fn from(handle: Handle) -> Self {
         handle.entry
@@ -191,21 +561,240 @@ pub(crate) fn new(when: u64, waker: &Waker, cancel_tx: Option<mpsc::Sender<Handl
     let mut entry = Box::new(Entry {
         pointers: linked_list::Pointers::new(),
         state: AtomicU8::new(STATE_UNREGISTERED),
-        when,
+        when: UnsafeCell::new(when),
         cancel_tx: Mutex::new(cancel_tx),
         waker: AtomicWaker::new(),
         handle: Handle {
             refs: refs.clone(),
             entry: NonNull::dangling(), // Will be set later
         },
+        cancel_next: UnsafeCell::new(std::ptr::null_mut()),
+        cancel_refs: UnsafeCell::new(std::ptr::null()),
+        owner_wheel: AtomicUsize::new(0),
+        owner_metrics: AtomicUsize::new(0),
     });
 
     entry.handle.entry = NonNull::from(entry.as_ref());
     entry.register_waker(waker);
     let entry_ptr = NonNull::from(Box::leak(entry));
 
+    // Not counted as registered yet: this entry doesn't have a worker (and
+    // so a `TimerMetrics`) to credit until it's actually inserted into a
+    // wheel -- see `set_owner_metrics`/`record_registered` at the
+    // `Insert::Success` call sites in `Timer::register` and
+    // `insert_inject_timers`.
+
     Handle {
         refs,
         entry: entry_ptr,
     }
 }
+
+impl crate::runtime::time::Wheel {
+    /// Moves `handle`, which must currently be `STATE_REGISTERED` in this
+    /// wheel, to the slot for `new_when`, reusing the entry instead of
+    /// letting the caller cancel and re-register a fresh one.
+    ///
+    /// Implemented in terms of the wheel's own `remove`/`insert` rather
+    /// than reaching into level/slot bookkeeping directly, since removing
+    /// and re-inserting is exactly what relocating an entry to a new slot
+    /// amounts to; the entry's identity (and its waker registration) is
+    /// preserved either way because `remove`/`insert` operate on the same
+    /// `Handle`.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must currently be linked into this wheel (`STATE_REGISTERED`),
+    /// matching `remove`'s own safety requirement.
+    pub(crate) unsafe fn reschedule(&mut self, handle: Handle, new_when: u64) {
+        let Some(cancel_tx) = handle.cancel_tx() else {
+            // No channel to re-register with. Shouldn't happen for a
+            // still-registered entry (one is always set by the insert
+            // that registered it), but there's nothing safe to do here
+            // beyond leaving the entry where it is.
+            return;
+        };
+
+        unsafe { self.remove(handle.clone()) };
+        unsafe { handle.set_when(new_when) };
+
+        // Mirrors `insert_inject_timers`'s handling of the same
+        // `Insert` outcome: `Insert::Elapsed` means `new_when` had
+        // already passed by the time it was reinserted (e.g. an
+        // `Interval` computing its next tick under load), and `insert`
+        // only flips the entry to `STATE_PREMATURE` without waking
+        // anyone. Silently discarding that outcome here (as the
+        // original `let _ = ...` did) meant the task waiting on this
+        // timer would never be woken.
+        match unsafe { self.insert(handle.clone(), cancel_tx) } {
+            Insert::Success => {}
+            Insert::Elapsed => {
+                self.metrics().record_premature();
+                if let Some(waker) = handle.take_waker_unregistered() {
+                    waker.wake();
+                }
+            }
+            Insert::Cancelling => {}
+        }
+    }
+
+    /// This worker's timer metrics, shared with its `Context2` (the same
+    /// instance `Timer::register`/`insert_inject_timers` record against).
+    pub(crate) fn metrics(&self) -> &TimerMetrics {
+        &self.metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        fn raw_waker() -> RawWaker {
+            static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        unsafe { Waker::from_raw(raw_waker()) }
+    }
+
+    #[test]
+    fn timer_metrics_track_registration_lifecycle() {
+        // A fresh, standalone `TimerMetrics` instance, the same way a
+        // worker's own instance is independent of every other worker's,
+        // so counts aren't perturbed by other tests/entries running
+        // concurrently in the same process.
+        let metrics = TimerMetrics::new();
+        assert_eq!(metrics.active_timers_count(), 0);
+
+        metrics.record_registered();
+        metrics.record_registered();
+        assert_eq!(metrics.active_timers_count(), 2);
+
+        metrics.record_deregistered();
+        assert_eq!(metrics.active_timers_count(), 1);
+
+        assert_eq!(metrics.premature_timers_total(), 0);
+        metrics.record_premature();
+        assert_eq!(metrics.premature_timers_total(), 1);
+
+        assert_eq!(metrics.cancelled_timers_total(), 0);
+        metrics.record_cancelled_processed();
+        assert_eq!(metrics.cancelled_timers_total(), 1);
+    }
+
+    #[test]
+    fn timer_metrics_next_expiration_round_trips() {
+        let metrics = TimerMetrics::new();
+        assert_eq!(metrics.next_timer_expiration(), None);
+
+        metrics.set_next_expiration(Some(42));
+        assert_eq!(metrics.next_timer_expiration(), Some(42));
+
+        metrics.set_next_expiration(None);
+        assert_eq!(metrics.next_timer_expiration(), None);
+    }
+
+    #[test]
+    fn owner_wheel_starts_unset() {
+        let waker = noop_waker();
+        let hdl = new(0, &waker, None);
+        let some_wheel = 0x1000 as *const Wheel;
+
+        assert!(!hdl.is_owned_by_wheel(some_wheel));
+    }
+
+    #[test]
+    fn owner_wheel_matches_only_the_recorded_wheel() {
+        let waker = noop_waker();
+        let hdl = new(0, &waker, None);
+        let owning_wheel = 0x1000 as *const Wheel;
+        let other_wheel = 0x2000 as *const Wheel;
+
+        hdl.set_owner_wheel(owning_wheel);
+
+        assert!(hdl.is_owned_by_wheel(owning_wheel));
+        assert!(!hdl.is_owned_by_wheel(other_wheel));
+    }
+
+    #[test]
+    fn owner_metrics_credited_on_final_drop() {
+        let metrics = TimerMetrics::new();
+        metrics.record_registered();
+        assert_eq!(metrics.active_timers_count(), 1);
+
+        let waker = noop_waker();
+        let hdl = new(0, &waker, None);
+        hdl.set_owner_metrics(&metrics as *const _);
+
+        // Dropping the last externally-held `Handle` should credit the
+        // deregistration to the `TimerMetrics` recorded via
+        // `set_owner_metrics`, not a crate-wide static.
+        drop(hdl);
+        assert_eq!(metrics.active_timers_count(), 0);
+    }
+
+    #[test]
+    fn cancel_stack_drains_most_recent_first() {
+        let waker = noop_waker();
+        let a = new(1, &waker, None);
+        let b = new(2, &waker, None);
+        let c = new(3, &waker, None);
+
+        let stack = CancelStack::new();
+        unsafe {
+            stack.push(a.clone());
+            stack.push(b.clone());
+            stack.push(c.clone());
+        }
+
+        let drained: Vec<_> = stack.drain().map(|h| h.entry).collect();
+        assert_eq!(drained, vec![c.entry, b.entry, a.entry]);
+    }
+
+    #[test]
+    fn cancel_stack_reconstructs_without_leaking_or_double_counting() {
+        let waker = noop_waker();
+        let hdl = new(10, &waker, None);
+        let baseline = hdl.refs.load(Ordering::Relaxed); // 2: self + `hdl`
+
+        let stack = CancelStack::new();
+        unsafe { stack.push(hdl.clone()) };
+
+        assert_eq!(hdl.refs.load(Ordering::Relaxed), baseline + 1);
+
+        let drained: Vec<_> = stack.drain().collect();
+        assert_eq!(drained.len(), 1);
+
+        assert_eq!(hdl.refs.load(Ordering::Relaxed), baseline + 1);
+
+        drop(drained);
+        assert_eq!(hdl.refs.load(Ordering::Relaxed), baseline);
+    }
+
+    #[test]
+    fn cancel_vs_fire_race_has_a_single_winner() {
+        use std::thread;
+
+        let waker = noop_waker();
+        let hdl = new(0, &waker, None);
+        hdl.transition_to_registered();
+
+        let hdl2 = hdl.clone();
+        let canceller = thread::spawn(move || hdl2.mark_cancelled());
+        let firer = thread::spawn(move || hdl.transition_to_pending(u64::MAX));
+
+        let cancel_outcome = canceller.join().unwrap();
+        let fire_result = firer.join().unwrap();
+
+        match (cancel_outcome, fire_result) {
+            (CancelOutcome::WasRegistered, Err(_)) => {}
+            (CancelOutcome::AlreadyGone, Ok(())) => {}
+            _ => panic!("inconsistent race outcome"),
+        }
+    }
+}